@@ -630,4 +630,15 @@ mod tests {
         let shape = Shape::from((299, 792, 458));
         assert_eq!(shape.stride_contiguous(), [458 * 792, 458, 1]);
     }
+
+    #[test]
+    fn negative_dims() {
+        let shape = Shape::from((2, 3, 4));
+        assert_eq!(D::Minus1.to_index(&shape, "test").unwrap(), 2);
+        assert_eq!(D::Minus2.to_index(&shape, "test").unwrap(), 1);
+        assert_eq!(D::Minus(3).to_index(&shape, "test").unwrap(), 0);
+        assert!(D::Minus(4).to_index(&shape, "test").is_err());
+        assert!(D::Minus(0).to_index(&shape, "test").is_err());
+        assert!(3usize.to_index(&shape, "test").is_err());
+    }
 }
@@ -1109,6 +1109,12 @@ impl Tensor {
         self.maximum(min)?.minimum(max)
     }
 
+    /// Broadcasting version of `clamp`, the `min` and `max` bounds are tensors that get
+    /// broadcasted against `self`, e.g. to use per-channel bounds.
+    pub fn broadcast_clamp(&self, min: &Self, max: &Self) -> Result<Self> {
+        self.broadcast_maximum(min)?.broadcast_minimum(max)
+    }
+
     /// Interpolate the input tensor to the `target_size` size, taking the value of the nearest element.
     ///
     /// The input tensor should have three dimensions, `(batch, channels, l)`, the returned
@@ -1318,6 +1324,20 @@ impl Tensor {
         Ok(from_storage(storage, shape, op, false))
     }
 
+    /// Broadcasting version of `where_cond`, the condition and the two value tensors are
+    /// broadcasted to a common shape, e.g. a `(n, 1)` mask can select between two `(n, m)`
+    /// tensors.
+    pub fn broadcast_where_cond(&self, on_true: &Self, on_false: &Self) -> Result<Self> {
+        let shape = self
+            .shape()
+            .broadcast_shape_binary_op(on_true.shape(), "broadcast_where_cond")?
+            .broadcast_shape_binary_op(on_false.shape(), "broadcast_where_cond")?;
+        self.broadcast_as(&shape)?.where_cond(
+            &on_true.broadcast_as(&shape)?,
+            &on_false.broadcast_as(&shape)?,
+        )
+    }
+
     /// Returns a tensor with the values from the `self` tensor at the index corresponding to the
     /// values hold in the `ids` tensor.
     ///
@@ -2355,6 +2375,30 @@ impl Tensor {
         }
     }
 
+    /// Pad the input tensor by reflecting its values along dimension `dim`, the edge values are
+    /// not repeated (this matches the PyTorch `reflect` mode). This adds `left` elements before
+    /// the input tensor values and `right` elements after, both have to be smaller than the size
+    /// of `dim`.
+    pub fn pad_with_reflect<D: Dim>(&self, dim: D, left: usize, right: usize) -> Result<Self> {
+        if left == 0 && right == 0 {
+            return Ok(self.clone());
+        }
+        let dim = dim.to_index(self.shape(), "pad_with_reflect")?;
+        let size = self.dim(dim)?;
+        if left >= size || right >= size {
+            bail!("pad_with_reflect: padding {left},{right} is too large for dim {dim} of size {size}")
+        }
+        let mut v = Vec::with_capacity(left + right + 1);
+        for i in (1..=left).rev() {
+            v.push(self.narrow(dim, i, 1)?)
+        }
+        v.push(self.clone());
+        for i in 1..=right {
+            v.push(self.narrow(dim, size - 1 - i, 1)?)
+        }
+        Tensor::cat(&v, dim)
+    }
+
     /// Run the `forward` method of `m` on `self`.
     pub fn apply<M: crate::Module>(&self, m: &M) -> Result<Self> {
         m.forward(self)
@@ -738,6 +738,9 @@ fn conv2d_grad(dev: &Device) -> Result<()> {
     let stride = 2;
 
     let res = t.conv_transpose2d(&w, padding, outpadding, stride, dilation)?;
+    // The output padding adds one row and one column on top of the strided output size,
+    // (7 - 1) * 2 - 2 + (3 - 1) + 1 + 1 = 14 and (5 - 1) * 2 - 2 + (5 - 1) + 1 + 1 = 12.
+    assert_eq!(res.dims(), [1, 2, 14, 12]);
     let loss = res.sqr()?.sum_all()?;
     assert_eq!(test_utils::to_vec0_round(&loss, 0)?, 3627.0); // torch gives 3626.8560
 
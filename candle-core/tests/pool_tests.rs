@@ -15,6 +15,17 @@ fn avg_pool2d(dev: &Device) -> Result<()> {
     let t = Tensor::from_vec(data, (1, 1, 2, 8), dev)?;
     let pool = t.avg_pool2d(2)?.squeeze(0)?.squeeze(0)?;
     assert_eq!(pool.to_vec2::<f32>()?, [[5. / 4., 6. / 4., 6. / 4., 1.]]);
+
+    // Non-square window, only pooling along the height.
+    let t = t.reshape((1, 1, 4, 4))?;
+    let pool = t
+        .avg_pool2d_with_stride((2, 1), (2, 1))?
+        .squeeze(0)?
+        .squeeze(0)?;
+    assert_eq!(
+        pool.to_vec2::<f32>()?,
+        [[0.5, 1., 1., 2.], [3., 1., 1., 1.]]
+    );
     Ok(())
 }
 
@@ -30,6 +41,22 @@ fn max_pool2d(dev: &Device) -> Result<()> {
     let t = t.reshape((1, 1, 2, 8))?;
     let pool = t.max_pool2d(2)?.squeeze(0)?.squeeze(0)?;
     assert_eq!(pool.to_vec2::<f32>()?, [[2.0, 3.0, 5.0, 1.0]]);
+
+    // Non-square window, only pooling along the width.
+    let t = t.reshape((1, 1, 4, 4))?;
+    let pool = t
+        .max_pool2d_with_stride((1, 3), (1, 1))?
+        .squeeze(0)?
+        .squeeze(0)?;
+    assert_eq!(
+        pool.to_vec2::<f32>()?,
+        [[2., 3.], [1., 1.], [1., 1.], [5., 1.]]
+    );
+    let pool = t
+        .max_pool2d_with_stride((2, 3), (1, 2))?
+        .squeeze(0)?
+        .squeeze(0)?;
+    assert_eq!(pool.to_vec2::<f32>()?, [[2.], [1.], [5.]]);
     Ok(())
 }
 
@@ -98,6 +125,31 @@ fn upsample_nearest2d(dev: &Device) -> Result<()> {
             [3.0, 3.0, 4.0, 4.0, 5.0, 5.0]
         ]
     );
+
+    // Non-integer scale factors, this matches torch.nn.functional.interpolate with
+    // mode="nearest" which uses src_idx = floor(dst_idx * src_size / dst_size).
+    let upsampled = t.upsample_nearest2d(3, 4)?.i(0)?.i(0)?;
+    assert_eq!(
+        upsampled.to_vec2::<f32>()?,
+        [
+            [0.0, 0.0, 1.0, 2.0],
+            [0.0, 0.0, 1.0, 2.0],
+            [3.0, 3.0, 4.0, 5.0]
+        ]
+    );
+    // Downsampling 7 -> 5 picks the source indexes 0, 1, 2, 4, 5.
+    let t = Tensor::arange(0f32, 49f32, dev)?.reshape((1, 1, 7, 7))?;
+    let downsampled = t.interpolate2d(5, 5)?.i(0)?.i(0)?;
+    assert_eq!(
+        downsampled.to_vec2::<f32>()?,
+        [
+            [0.0, 1.0, 2.0, 4.0, 5.0],
+            [7.0, 8.0, 9.0, 11.0, 12.0],
+            [14.0, 15.0, 16.0, 18.0, 19.0],
+            [28.0, 29.0, 30.0, 32.0, 33.0],
+            [35.0, 36.0, 37.0, 39.0, 40.0]
+        ]
+    );
     Ok(())
 }
 
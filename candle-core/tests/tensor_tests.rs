@@ -93,6 +93,19 @@ fn clamp(device: &Device) -> Result<()> {
         tensor.to_vec2::<f32>()?,
         [[3.0, 1.5, 4.0, 1.5, 5.0], [2.0, 1.5, 6.2, 6.2, 2.0]],
     );
+
+    let tensor = Tensor::arange(0f32, 12f32, device)?.reshape((1, 3, 4))?;
+    let min = Tensor::new(&[[1f32], [5.], [9.]], device)?;
+    let max = Tensor::new(&[[2f32], [6.], [10.]], device)?;
+    let tensor = tensor.broadcast_clamp(&min, &max)?;
+    assert_eq!(
+        tensor.to_vec3::<f32>()?,
+        [[
+            [1.0, 1.0, 2.0, 2.0],
+            [5.0, 5.0, 6.0, 6.0],
+            [9.0, 9.0, 10.0, 10.0]
+        ]],
+    );
     Ok(())
 }
 
@@ -222,6 +235,14 @@ fn binary_op(device: &Device) -> Result<()> {
         max.to_vec2::<f32>()?,
         [[3.0, 2.5, 4.0, 2.5, 5.0], [2.0, 1.0, 7.0, 8.0, 2.0]]
     );
+
+    // Both operands are views on the same storage with different non-zero start offsets.
+    let t = Tensor::arange(0f32, 10f32, device)?;
+    let sum = (t.narrow(0, 1, 4)? + t.narrow(0, 5, 4)?)?;
+    assert_eq!(sum.to_vec1::<f32>()?, [6.0, 8.0, 10.0, 12.0]);
+    let t = t.reshape((2, 5))?;
+    let sum = (t.narrow(1, 0, 2)? + t.narrow(1, 3, 2)?)?;
+    assert_eq!(sum.to_vec2::<f32>()?, [[3.0, 5.0], [13.0, 15.0]]);
     Ok(())
 }
 
@@ -474,6 +495,13 @@ fn max(device: &Device) -> Result<()> {
             ]]
         );
     }
+
+    // Rows made only of -inf, e.g. fully masked attention scores, reduce to -inf.
+    let inf = f32::INFINITY;
+    let tensor = Tensor::new(&[[-inf, -inf, -inf], [-inf, 1., -inf]], device)?;
+    assert_eq!(tensor.max(1)?.to_vec1::<f32>()?, &[-inf, 1.]);
+    let tensor = tensor.neg()?;
+    assert_eq!(tensor.min(1)?.to_vec1::<f32>()?, &[inf, -1.]);
     Ok(())
 }
 
@@ -769,6 +797,22 @@ fn cat(device: &Device) -> Result<()> {
     Ok(())
 }
 
+fn where_cond(device: &Device) -> Result<()> {
+    let mask = Tensor::new(&[[1u8, 0, 1], [0, 1, 0]], device)?;
+    let on_true = Tensor::arange(0f32, 6f32, device)?.reshape((2, 3))?;
+    let on_false = on_true.neg()?;
+    let res = mask.where_cond(&on_true, &on_false)?;
+    assert_eq!(res.to_vec2::<f32>()?, [[0., -1., 2.], [-3., 4., -5.]]);
+
+    let mask = Tensor::new(&[[1u8], [0]], device)?;
+    let res = mask.broadcast_where_cond(&on_true, &on_false)?;
+    assert_eq!(res.to_vec2::<f32>()?, [[0., 1., 2.], [-3., -4., -5.]]);
+    let on_false = Tensor::new(&[-1f32, -2., -3.], device)?;
+    let res = mask.broadcast_where_cond(&on_true, &on_false)?;
+    assert_eq!(res.to_vec2::<f32>()?, [[0., 1., 2.], [-1., -2., -3.]]);
+    Ok(())
+}
+
 fn embeddings(device: &Device) -> Result<()> {
     let ids = Tensor::new(&[0u32, 2u32, 1u32], device)?;
     let t = Tensor::new(&[[0f32, 1f32], [2f32, 3f32], [4f32, 5f32]], device)?;
@@ -790,6 +834,21 @@ fn cmp(device: &Device) -> Result<()> {
     assert_eq!(t1.lt(&t2)?.to_vec2::<u8>()?, &[[1, 0], [1, 0], [0, 1]]);
     assert_eq!(t1.gt(&t2)?.to_vec2::<u8>()?, &[[0, 1], [0, 0], [0, 0]]);
     assert_eq!(t1.ge(&t2)?.to_vec2::<u8>()?, &[[0, 1], [0, 1], [1, 0]]);
+
+    // Compare against a broadcasted threshold row.
+    let t3 = Tensor::new(&[[2f32, 3f32]], device)?;
+    assert_eq!(
+        t1.broadcast_gt(&t3)?.to_vec2::<u8>()?,
+        &[[0, 0], [0, 0], [1, 1]]
+    );
+    assert_eq!(
+        t1.broadcast_le(&t3)?.to_vec2::<u8>()?,
+        &[[1, 1], [1, 1], [0, 0]]
+    );
+    assert_eq!(
+        t3.broadcast_eq(&t1)?.to_vec2::<u8>()?,
+        &[[0, 0], [1, 1], [0, 0]]
+    );
     Ok(())
 }
 
@@ -890,6 +949,28 @@ fn index_add(device: &Device) -> Result<()> {
             [0.0, 1.0, 2.0]
         ]
     );
+
+    let init = Tensor::zeros((2, 5), DType::F32, device)?;
+    let ids = Tensor::new(&[1u32, 3u32], device)?;
+    let src = Tensor::new(&[[1f32, 2.], [3., 4.]], device)?;
+    let hs = init.index_add(&ids, &src, 1)?;
+    assert_eq!(
+        hs.to_vec2::<f32>()?,
+        &[[0.0, 1.0, 0.0, 2.0, 0.0], [0.0, 3.0, 0.0, 4.0, 0.0]],
+    );
+
+    // Adding along a middle axis of a rank 3 tensor.
+    let init = Tensor::zeros((2, 3, 2), DType::F32, device)?;
+    let ids = Tensor::new(&[2u32, 0u32], device)?;
+    let src = Tensor::arange(0f32, 8f32, device)?.reshape((2, 2, 2))?;
+    let hs = init.index_add(&ids, &src, 1)?;
+    assert_eq!(
+        hs.to_vec3::<f32>()?,
+        &[
+            [[2.0, 3.0], [0.0, 0.0], [0.0, 1.0]],
+            [[6.0, 7.0], [0.0, 0.0], [4.0, 5.0]]
+        ],
+    );
     Ok(())
 }
 
@@ -972,6 +1053,13 @@ fn scatter_add(device: &Device) -> Result<()> {
             [1.0, 1.0, 1.0]
         ]
     );
+
+    // Taking along an axis with a permutation then putting back restores the original.
+    let t = Tensor::arange(0f32, 12f32, device)?.reshape((3, 4))?;
+    let ids = Tensor::new(&[[3u32, 0, 2, 1], [1, 2, 3, 0], [0, 1, 2, 3]], device)?;
+    let taken = t.gather(&ids, 1)?;
+    let put = t.zeros_like()?.scatter_add(&ids, &taken, 1)?;
+    assert_eq!(put.to_vec2::<f32>()?, t.to_vec2::<f32>()?);
     Ok(())
 }
 
@@ -1004,6 +1092,12 @@ fn gather(device: &Device) -> Result<()> {
     let ids = Tensor::new(&[[0u32, 2u32, 0u32], [0u32, 1u32, 1u32]], device)?;
     let hs = t.gather(&ids, 0)?;
     assert_eq!(hs.to_vec2::<f32>()?, &[[0.0, 7.0, 2.0], [0.0, 4.0, 5.0]]);
+
+    // Element-wise selection along the last axis, the index has fewer columns than the source.
+    let t = Tensor::arange(0f32, 8f32, device)?.reshape((2, 4))?;
+    let ids = Tensor::new(&[[3u32, 0u32], [1u32, 1u32]], device)?;
+    let hs = t.gather(&ids, 1)?;
+    assert_eq!(hs.to_vec2::<f32>()?, &[[3.0, 0.0], [5.0, 5.0]]);
     Ok(())
 }
 
@@ -1182,6 +1276,7 @@ test_device!(unary_op, unary_op_cpu, unary_op_gpu, unary_op_metal);
 test_device!(binary_op, binary_op_cpu, binary_op_gpu, binary_op_metal);
 test_device!(embeddings, embeddings_cpu, embeddings_gpu, embeddings_metal);
 test_device!(cmp, cmp_cpu, cmp_gpu, cmp_metal);
+test_device!(where_cond, where_cond_cpu, where_cond_gpu, where_cond_metal);
 test_device!(
     broadcasting,
     broadcasting_cpu,
@@ -1241,6 +1336,46 @@ fn pad_with_same() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn pad_with_reflect() -> Result<()> {
+    let t = Tensor::arange(1f32, 6f32, &Device::Cpu)?.reshape((1, 1, 5))?;
+    let t0 = t.pad_with_reflect(2, 2, 2)?;
+    assert_eq!(
+        t0.to_vec3::<f32>()?,
+        [[[3.0, 2.0, 1.0, 2.0, 3.0, 4.0, 5.0, 4.0, 3.0]]]
+    );
+    let t1 = t.pad_with_reflect(2, 0, 1)?;
+    assert_eq!(t1.to_vec3::<f32>()?, [[[1.0, 2.0, 3.0, 4.0, 5.0, 4.0]]]);
+    let t = Tensor::arange(1f32, 7f32, &Device::Cpu)?.reshape((3, 2))?;
+    let t2 = t.pad_with_reflect(0, 1, 2)?;
+    assert_eq!(
+        t2.to_vec2::<f32>()?,
+        [
+            [3.0, 4.0],
+            [1.0, 2.0],
+            [3.0, 4.0],
+            [5.0, 6.0],
+            [3.0, 4.0],
+            [1.0, 2.0]
+        ]
+    );
+    // The padding has to be strictly smaller than the padded dimension.
+    assert!(t.pad_with_reflect(1, 2, 0).is_err());
+    Ok(())
+}
+
+#[test]
+fn normalize_axis() -> Result<()> {
+    let t = Tensor::zeros((2, 3, 4), DType::F32, &Device::Cpu)?;
+    assert_eq!(t.normalize_axis(-1)?, 2);
+    assert_eq!(t.normalize_axis(-2)?, 1);
+    assert_eq!(t.normalize_axis(-3)?, 0);
+    assert_eq!(t.normalize_axis(1)?, 1);
+    assert!(t.normalize_axis(-4).is_err());
+    assert!(t.normalize_axis(3).is_err());
+    Ok(())
+}
+
 #[test]
 fn i64_abs() -> Result<()> {
     let t = Tensor::new(&[-42i64, 1337], &Device::Cpu)?;
@@ -83,3 +83,45 @@ pub fn group_norm(
     let bias = vb.get_with_hints(num_channels, "bias", crate::Init::Const(0.))?;
     GroupNorm::new(weight, bias, num_channels, num_groups, eps)
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InstanceNormConfig {
+    pub eps: f64,
+    /// When false there is no learnable parameter, 1 is used for the weight and 0 for the bias
+    /// and nothing is read from the var builder.
+    pub affine: bool,
+}
+
+impl Default for InstanceNormConfig {
+    fn default() -> Self {
+        Self {
+            eps: 1e-5,
+            affine: true,
+        }
+    }
+}
+
+impl From<f64> for InstanceNormConfig {
+    fn from(eps: f64) -> Self {
+        Self {
+            eps,
+            ..Default::default()
+        }
+    }
+}
+
+/// Instance Normalization, this is a group norm where each channel is its own group so the
+/// statistics are computed per sample and per channel over the spatial dimensions.
+pub fn instance_norm<C: Into<InstanceNormConfig>>(
+    num_channels: usize,
+    config: C,
+    vb: crate::VarBuilder,
+) -> Result<GroupNorm> {
+    let config = config.into();
+    if config.affine {
+        return group_norm(num_channels, num_channels, config.eps, vb);
+    }
+    let weight = Tensor::ones(num_channels, vb.dtype(), vb.device())?;
+    let bias = Tensor::zeros(num_channels, vb.dtype(), vb.device())?;
+    GroupNorm::new(weight, bias, num_channels, num_channels, config.eps)
+}
@@ -27,7 +27,7 @@ pub use conv::{
 };
 pub use embedding::{embedding, Embedding};
 pub use func::{func, func_t, Func, FuncT};
-pub use group_norm::{group_norm, GroupNorm};
+pub use group_norm::{group_norm, instance_norm, GroupNorm, InstanceNormConfig};
 pub use init::Init;
 pub use layer_norm::{layer_norm, rms_norm, LayerNorm, LayerNormConfig, RmsNorm};
 pub use linear::{linear, linear_b, linear_no_bias, Linear};
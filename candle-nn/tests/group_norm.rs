@@ -26,7 +26,7 @@ extern crate accelerate_src;
 
 use anyhow::Result;
 use candle::test_utils::to_vec3_round;
-use candle::{Device, Tensor};
+use candle::{DType, Device, Tensor};
 use candle_nn::{GroupNorm, Module};
 
 #[test]
@@ -103,3 +103,47 @@ fn group_norm() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn instance_norm() -> Result<()> {
+    let device = &Device::Cpu;
+    let w = Tensor::new(&[0.5f32, 1.0, 2.0], device)?;
+    let b = Tensor::new(&[0.1f32, -0.2, 0.3], device)?;
+    let input = Tensor::randn(0f32, 1f32, (2, 3, 8, 8), device)?;
+    let tensors: std::collections::HashMap<_, _> = [
+        ("weight".to_string(), w.clone()),
+        ("bias".to_string(), b.clone()),
+    ]
+    .into_iter()
+    .collect();
+    let vb = candle_nn::VarBuilder::from_tensors(tensors, DType::F32, device);
+    let inorm = candle_nn::instance_norm(3, 1e-5, vb)?;
+    let ys = inorm.forward(&input)?;
+
+    let xs = input.flatten_from(2)?;
+    let mean = xs.mean_keepdim(2)?;
+    let xs = xs.broadcast_sub(&mean)?;
+    let var = xs.sqr()?.mean_keepdim(2)?;
+    let expected = xs
+        .broadcast_div(&(&var + 1e-5)?.sqrt()?)?
+        .broadcast_mul(&w.reshape((1, 3, 1))?)?
+        .broadcast_add(&b.reshape((1, 3, 1))?)?
+        .reshape((2, 3, 8, 8))?;
+    let diff = (ys - expected)?.abs()?.flatten_all()?.max(0)?;
+    assert!(diff.to_vec0::<f32>()? < 1e-5);
+
+    // Without affine parameters, nothing gets loaded from the var builder.
+    let vb = candle_nn::VarBuilder::from_tensors(Default::default(), DType::F32, device);
+    let config = candle_nn::InstanceNormConfig {
+        eps: 1e-5,
+        affine: false,
+    };
+    let inorm = candle_nn::instance_norm(3, config, vb)?;
+    let ys = inorm.forward(&input)?;
+    let expected = xs
+        .broadcast_div(&(var + 1e-5)?.sqrt()?)?
+        .reshape((2, 3, 8, 8))?;
+    let diff = (ys - expected)?.abs()?.flatten_all()?.max(0)?;
+    assert!(diff.to_vec0::<f32>()? < 1e-5);
+    Ok(())
+}
@@ -0,0 +1,63 @@
+#[cfg(feature = "mkl")]
+extern crate intel_mkl_src;
+
+#[cfg(feature = "accelerate")]
+extern crate accelerate_src;
+
+use candle::{Device, Result, Tensor};
+
+#[test]
+fn kv_cache() -> Result<()> {
+    let mut cache = candle_nn::kv_cache::Cache::new(0, 16);
+    let data = cache.current_data()?;
+    assert!(data.is_none());
+    let t = Tensor::new(&[1f32, 2., 3.], &Device::Cpu)?;
+    cache.append(&t)?;
+    let data = cache.current_data()?.unwrap();
+    assert_eq!(data.to_vec1::<f32>()?, [1., 2., 3.]);
+    let t = Tensor::new(&[4f32], &Device::Cpu)?;
+    cache.append(&t)?;
+    let data = cache.current_data()?.unwrap();
+    assert_eq!(data.to_vec1::<f32>()?, [1., 2., 3., 4.]);
+    let t = Tensor::new(&[0f32, 5., 6., 7.], &Device::Cpu)?;
+    cache.append(&t)?;
+    let data = cache.current_data()?.unwrap();
+    assert_eq!(data.to_vec1::<f32>()?, [1., 2., 3., 4., 0., 5., 6., 7.]);
+    assert_eq!(cache.current_seq_len(), 8);
+    cache.reset();
+    assert_eq!(cache.current_seq_len(), 0);
+    let data = cache.current_data()?;
+    assert!(data.is_none());
+    Ok(())
+}
+
+#[test]
+fn kv_cache_bhsd() -> Result<()> {
+    let (b_sz, heads, dim) = (2, 3, 4);
+    let mut cache = candle_nn::kv_cache::KvCache::new(2, 4);
+    let k = Tensor::arange(0f32, 48f32, &Device::Cpu)?.reshape((b_sz, heads, 2, dim))?;
+    let v = k.neg()?;
+    let (k_all, v_all) = cache.append(&k, &v)?;
+    assert_eq!(k_all.dims(), [b_sz, heads, 2, dim]);
+    assert_eq!(
+        v_all.flatten_all()?.to_vec1::<f32>()?,
+        v.flatten_all()?.to_vec1::<f32>()?
+    );
+
+    let k1 = Tensor::ones((b_sz, heads, 1, dim), candle::DType::F32, &Device::Cpu)?.neg()?;
+    let (k_all, _) = cache.append(&k1, &k1)?;
+    assert_eq!(k_all.dims(), [b_sz, heads, 3, dim]);
+    assert_eq!(
+        k_all.narrow(2, 0, 2)?.flatten_all()?.to_vec1::<f32>()?,
+        k.flatten_all()?.to_vec1::<f32>()?
+    );
+    assert_eq!(
+        k_all.narrow(2, 2, 1)?.flatten_all()?.to_vec1::<f32>()?,
+        [-1f32; 24]
+    );
+
+    // Appending two more positions would go past the capacity of 4.
+    let k2 = Tensor::zeros((b_sz, heads, 2, dim), candle::DType::F32, &Device::Cpu)?;
+    assert!(cache.append(&k2, &k2).is_err());
+    Ok(())
+}
@@ -112,6 +112,28 @@ fn softmax_numerical_stability() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn softmax_last_dim_grad() -> Result<()> {
+    let dev = &Device::Cpu;
+    let xs = candle::Var::new(&[[1f32, 2., -3., 0.5], [0.1, -1., 4., 2.]], dev)?;
+    let w = Tensor::new(&[[3f32, -1., 2., 0.], [1., 1., -2., 5.]], dev)?;
+
+    // Reference gradient using the differentiable composed softmax.
+    let loss = (candle_nn::ops::softmax(&xs, 1)? * &w)?.sum_all()?;
+    let grads = loss.backward()?;
+    let expected = grads.get(&xs).unwrap();
+
+    let loss = (candle_nn::ops::softmax_last_dim(&xs)? * &w)?.sum_all()?;
+    let grads = loss.backward()?;
+    let grad = grads.get(&xs).unwrap();
+    let diff = (grad - expected)?.abs()?.sum_all()?.to_vec0::<f32>()?;
+    assert!(diff < 1e-5, "{diff}");
+    // The softmax sums to one on each row so the gradient rows sum to zero.
+    let row_sums = grad.sum(1)?.abs()?.to_vec1::<f32>()?;
+    assert!(row_sums.iter().all(|v| *v < 1e-5));
+    Ok(())
+}
+
 fn ropei(device: &Device) -> Result<()> {
     use rand::{rngs::StdRng, Rng, SeedableRng};
 
@@ -136,6 +158,14 @@ fn ropei(device: &Device) -> Result<()> {
     } else {
         assert!(sum_diff < 1e-4);
     }
+
+    // The sin table is checked on its own, a mismatch with cos is an error.
+    let short_sin = sin.narrow(1, 0, head_dim / 4)?.contiguous()?;
+    assert!(candle_nn::rotary_emb::rope_i(&src, &cos, &short_sin).is_err());
+    // An odd head dimension cannot be split in pairs.
+    let odd_src = src.narrow(3, 0, head_dim - 1)?.contiguous()?;
+    assert!(candle_nn::rotary_emb::rope_i(&odd_src, &cos, &sin).is_err());
+    assert!(candle_nn::rotary_emb::rope(&odd_src, &cos, &sin).is_err());
     Ok(())
 }
 
@@ -206,6 +236,20 @@ fn sigmoid(device: &Device) -> Result<()> {
     Ok(())
 }
 
+fn swiglu(device: &Device) -> Result<()> {
+    let data = &[[3f32, 1., 4., 1.], [5., 9., 2., -6.], [-5., 3., 5., 8.]];
+    let tensor = Tensor::new(data, device)?;
+    let s1 = candle_nn::ops::swiglu(&tensor)?;
+    assert_eq!(s1.dims(), [3, 2]);
+    // The first half of the last dimension is the gate, the second half the value.
+    let gate = tensor.narrow(1, 0, 2)?;
+    let up = tensor.narrow(1, 2, 2)?;
+    let s2 = ((&gate / (1. + gate.neg()?.exp()?)?)? * up)?;
+    let diff = (s1 - s2)?.abs()?.sum_all()?.to_vec0::<f32>()?;
+    assert!(diff < 1e-5);
+    Ok(())
+}
+
 test_device!(ropei, ropei_cpu, ropei_gpu, ropei_metal);
 test_device!(rope, rope_cpu, rope_gpu, rope_metal);
 test_device!(rope_thd, rope_thd_cpu, rope_thd_gpu, rope_thd_metal);
@@ -213,3 +257,4 @@ test_device!(softmax, softmax_cpu, softmax_gpu, softmax_metal);
 test_device!(rms_norm, rms_norm_cpu, rms_norm_gpu, rms_norm_metal);
 test_device!(layer_norm, ln_cpu, ln_gpu, ln_metal);
 test_device!(sigmoid, sigmoid_cpu, sigmoid_gpu, sigmoid_metal);
+test_device!(swiglu, swiglu_cpu, swiglu_gpu, swiglu_metal);